@@ -126,6 +126,55 @@ pub enum ExprKind {
     Variable(Path),
     Constant(Constant),
     Block(Block),
+    /// A binary operation.
+    /// E.g., `a + b`
+    Binary(BinOpKind, P<Expr>, P<Expr>),
+    /// A unary operation.
+    /// E.g., `-a`
+    Unary(UnOpKind, P<Expr>),
+    /// A function call.
+    /// E.g., `foo(a, b)`
+    Call(Path, Vec<Expr>),
+    /// A conditional expression.
+    /// E.g., `if a { b } else { c }`
+    If {
+        cond: P<Expr>,
+        then: P<Block>,
+        els: Option<P<Expr>>,
+    },
+    /// A while loop.
+    /// E.g., `while a { b }`
+    While { cond: P<Expr>, body: P<Block> },
+    /// A loop that runs until it is broken out of.
+    /// E.g., `loop { b }`
+    Loop(P<Block>),
+}
+
+/// A binary operator
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum BinOpKind {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    And,
+    Or,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// A unary operator
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum UnOpKind {
+    /// Negation (`-a`)
+    Neg,
+    /// Logical inversion (`!a`)
+    Not,
 }
 
 /// A constant
@@ -200,9 +200,49 @@ impl<T: Visitor<K, V>, K, V> Visitable<T, K, V> for Expr {
         let (visit_result, res) = visitor.apply(&mut ASTNodeEnum::Expr(self))?;
         if visit_result {
             match self.kind {
-                ExprKind::Constant(ref mut c) => c.visit(visitor)?,
-                ExprKind::Variable(ref mut p) => p.visit(visitor)?,
-                ExprKind::Block(ref mut b) => b.visit(visitor)?,
+                ExprKind::Constant(ref mut c) => {
+                    c.visit(visitor)?;
+                }
+                ExprKind::Variable(ref mut p) => {
+                    p.visit(visitor)?;
+                }
+                ExprKind::Block(ref mut b) => {
+                    b.visit(visitor)?;
+                }
+                ExprKind::Binary(_, ref mut lhs, ref mut rhs) => {
+                    lhs.visit(visitor)?;
+                    rhs.visit(visitor)?;
+                }
+                ExprKind::Unary(_, ref mut e) => {
+                    e.visit(visitor)?;
+                }
+                ExprKind::Call(ref mut path, ref mut args) => {
+                    path.visit(visitor)?;
+                    for arg in args.iter_mut() {
+                        arg.visit(visitor)?;
+                    }
+                }
+                ExprKind::If {
+                    ref mut cond,
+                    ref mut then,
+                    ref mut els,
+                } => {
+                    cond.visit(visitor)?;
+                    then.visit(visitor)?;
+                    if let Some(ref mut els) = els {
+                        els.visit(visitor)?;
+                    }
+                }
+                ExprKind::While {
+                    ref mut cond,
+                    ref mut body,
+                } => {
+                    cond.visit(visitor)?;
+                    body.visit(visitor)?;
+                }
+                ExprKind::Loop(ref mut body) => {
+                    body.visit(visitor)?;
+                }
             };
         }
         Ok(res)
@@ -255,3 +295,73 @@ impl<T: Visitor<K, V>, K, V> Visitable<T, K, V> for Block {
         Ok(res)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ptr::P;
+
+    /// A visitor that counts every node it is applied to.
+    struct CountingVisitor {
+        count: usize,
+    }
+
+    impl Visitor<(), ()> for CountingVisitor {
+        fn apply(&mut self, _ast_node: &mut ASTNodeEnum) -> GenericVisitApplyResult<(), ()> {
+            self.count += 1;
+            Ok((true, None))
+        }
+    }
+
+    fn int_expr(n: i64) -> Expr {
+        Expr {
+            kind: ExprKind::Constant(Constant {
+                kind: ConstantKind::Int(n),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_visit_binary_visits_both_operands() {
+        let mut expr = Expr {
+            kind: ExprKind::Binary(BinOpKind::Add, P::new(int_expr(1)), P::new(int_expr(2))),
+        };
+        let mut visitor = CountingVisitor { count: 0 };
+        expr.visit(&mut visitor).unwrap();
+        // The Binary expr itself, plus an Expr and a Constant for each operand.
+        assert_eq!(visitor.count, 5);
+    }
+
+    #[test]
+    fn test_visit_call_visits_path_and_all_args() {
+        let mut expr = Expr {
+            kind: ExprKind::Call(
+                Path {
+                    segments: vec![PathSegment {
+                        ident: "foo".to_string(),
+                    }],
+                },
+                vec![int_expr(1), int_expr(2), int_expr(3)],
+            ),
+        };
+        let mut visitor = CountingVisitor { count: 0 };
+        expr.visit(&mut visitor).unwrap();
+        // The Call expr, the Path, its one segment, and an Expr+Constant for each of the three args.
+        assert_eq!(visitor.count, 9);
+    }
+
+    #[test]
+    fn test_visit_if_visits_cond_then_and_els() {
+        let mut expr = Expr {
+            kind: ExprKind::If {
+                cond: P::new(int_expr(1)),
+                then: P::new(Block { stmts: vec![] }),
+                els: Some(P::new(int_expr(2))),
+            },
+        };
+        let mut visitor = CountingVisitor { count: 0 };
+        expr.visit(&mut visitor).unwrap();
+        // The If expr, the cond (Expr+Constant), the then Block, and the els (Expr+Constant).
+        assert_eq!(visitor.count, 6);
+    }
+}
@@ -0,0 +1,383 @@
+use crate::ast::{Block, Function, Instruction, InstructionKind};
+use blastf_ast::ast::{BinOpKind, ConstantKind, Expr, ExprKind, StatementKind, UnOpKind};
+
+/// An error produced while lowering `blastf_ast` into hmasm.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LowerError {
+    /// Control flow (`block`, `if`, `while`, `loop`) was used where a condition or operand
+    /// expression was expected, e.g. as the condition of an `if` or an argument to a call.
+    InvalidCondition,
+    /// A statement kind that this lowering stage does not yet support, e.g. a `let` binding.
+    UnsupportedStatement,
+}
+
+/// Lowers `blastf_ast` expressions into hmasm instructions.
+///
+/// This only covers the control-flow and operator forms introduced alongside it: `If` lowers
+/// to a `Chain` built around `execute if <cond> run`, and `While`/`Loop` lower to a generated
+/// function that calls itself while its condition holds. `let` bindings and definitions inside
+/// a lowered block are not yet supported, since this compiler has no scoreboard/value model
+/// yet to assign them storage.
+pub struct Lowerer {
+    /// Functions generated while lowering, e.g. the bodies of `while`/`loop` expressions.
+    /// These need to be appended to the enclosing `File` alongside the function being lowered.
+    generated: Vec<Function>,
+    next_loop_id: usize,
+}
+
+impl Lowerer {
+    /// Create a new, empty lowerer.
+    pub fn new() -> Lowerer {
+        Lowerer {
+            generated: Vec::new(),
+            next_loop_id: 0,
+        }
+    }
+
+    /// Take the functions generated so far, e.g. to append to a `File`.
+    pub fn take_generated(&mut self) -> Vec<Function> {
+        std::mem::take(&mut self.generated)
+    }
+
+    /// Lower a block of statements into an hmasm block.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LowerError::UnsupportedStatement` for any statement that isn't a plain
+    /// expression (e.g. a `let` binding), rather than silently dropping it.
+    pub fn lower_block(&mut self, block: &blastf_ast::ast::Block) -> Result<Block, LowerError> {
+        let mut instructions = Vec::new();
+        for stmt in &block.stmts {
+            match &stmt.kind {
+                StatementKind::Expr(ref expr) => instructions.push(self.lower_expr(expr)?),
+                _ => return Err(LowerError::UnsupportedStatement),
+            }
+        }
+        Ok(Block { instructions })
+    }
+
+    /// Lower a single expression into an hmasm instruction.
+    pub fn lower_expr(&mut self, expr: &Expr) -> Result<Instruction, LowerError> {
+        match &expr.kind {
+            ExprKind::Block(block) => Ok(Instruction {
+                kind: InstructionKind::Block(self.lower_block(block)?),
+            }),
+            ExprKind::If { cond, then, els } => {
+                let then_instruction = Instruction {
+                    kind: InstructionKind::Block(self.lower_block(then)?),
+                };
+                let if_chain = execute_if(cond, then_instruction)?;
+                match els {
+                    None => Ok(if_chain),
+                    Some(els) => {
+                        let else_instruction = self.lower_expr(els)?;
+                        Ok(Instruction {
+                            kind: InstructionKind::Block(Block {
+                                instructions: vec![
+                                    if_chain,
+                                    execute_unless(cond, else_instruction)?,
+                                ],
+                            }),
+                        })
+                    }
+                }
+            }
+            ExprKind::While { cond, body } => {
+                let name = self.fresh_loop_name();
+                let mut instructions = self.lower_block(body)?.instructions;
+                instructions.push(execute_if(cond, call_function(&name))?);
+                self.generated.push(Function {
+                    name: name.clone(),
+                    block: Block { instructions },
+                });
+                execute_if(cond, call_function(&name))
+            }
+            ExprKind::Loop(body) => {
+                let name = self.fresh_loop_name();
+                let mut instructions = self.lower_block(body)?.instructions;
+                instructions.push(call_function(&name));
+                self.generated.push(Function {
+                    name: name.clone(),
+                    block: Block { instructions },
+                });
+                Ok(call_function(&name))
+            }
+            _ => Ok(Instruction {
+                kind: InstructionKind::Command(stringify_expr(expr)?),
+            }),
+        }
+    }
+
+    fn fresh_loop_name(&mut self) -> String {
+        let id = self.next_loop_id;
+        self.next_loop_id += 1;
+        format!("__loop_{}", id)
+    }
+}
+
+/// `execute if <cond> run <then>`
+fn execute_if(cond: &Expr, then: Instruction) -> Result<Instruction, LowerError> {
+    Ok(Instruction {
+        kind: InstructionKind::Chain(
+            Box::new(Instruction {
+                kind: InstructionKind::Command(format!("execute if {} run", stringify_expr(cond)?)),
+            }),
+            Box::new(then),
+        ),
+    })
+}
+
+/// `execute unless <cond> run <then>`
+fn execute_unless(cond: &Expr, then: Instruction) -> Result<Instruction, LowerError> {
+    Ok(Instruction {
+        kind: InstructionKind::Chain(
+            Box::new(Instruction {
+                kind: InstructionKind::Command(format!(
+                    "execute unless {} run",
+                    stringify_expr(cond)?
+                )),
+            }),
+            Box::new(then),
+        ),
+    })
+}
+
+/// `function <name>`
+fn call_function(name: &str) -> Instruction {
+    Instruction {
+        kind: InstructionKind::Call("function".to_string(), name.to_string(), String::new()),
+    }
+}
+
+/// Renders an expression as plain command text.
+///
+/// This is a placeholder: the compiler has no scoreboard/value model yet, so variables,
+/// constants, and operators are rendered textually instead of compiled into real Minecraft
+/// score checks.
+///
+/// # Errors
+///
+/// Returns `LowerError::InvalidCondition` if `expr` is `Block`, `If`, `While`, or `Loop`.
+/// Nothing in this compiler currently rejects those as a condition or call argument before
+/// lowering runs, so this has to be a recoverable error rather than a panic.
+fn stringify_expr(expr: &Expr) -> Result<String, LowerError> {
+    match &expr.kind {
+        ExprKind::Variable(path) => Ok(path
+            .segments
+            .iter()
+            .map(|s| s.ident.clone())
+            .collect::<Vec<_>>()
+            .join("::")),
+        ExprKind::Constant(c) => Ok(match &c.kind {
+            ConstantKind::Int(n) => n.to_string(),
+            ConstantKind::Float(n) => n.to_string(),
+            ConstantKind::Bool(b) => b.to_string(),
+            ConstantKind::String(s) => s.clone(),
+        }),
+        ExprKind::Binary(op, lhs, rhs) => Ok(format!(
+            "{} {} {}",
+            stringify_expr(lhs)?,
+            bin_op_str(*op),
+            stringify_expr(rhs)?
+        )),
+        ExprKind::Unary(op, e) => Ok(format!("{}{}", un_op_str(*op), stringify_expr(e)?)),
+        ExprKind::Call(path, args) => Ok(format!(
+            "{}({})",
+            path.segments
+                .iter()
+                .map(|s| s.ident.clone())
+                .collect::<Vec<_>>()
+                .join("::"),
+            args.iter()
+                .map(stringify_expr)
+                .collect::<Result<Vec<_>, _>>()?
+                .join(", ")
+        )),
+        ExprKind::Block(_) | ExprKind::If { .. } | ExprKind::While { .. } | ExprKind::Loop(_) => {
+            Err(LowerError::InvalidCondition)
+        }
+    }
+}
+
+fn bin_op_str(op: BinOpKind) -> &'static str {
+    match op {
+        BinOpKind::Add => "+",
+        BinOpKind::Sub => "-",
+        BinOpKind::Mul => "*",
+        BinOpKind::Div => "/",
+        BinOpKind::Rem => "%",
+        BinOpKind::And => "&&",
+        BinOpKind::Or => "||",
+        BinOpKind::Eq => "==",
+        BinOpKind::Ne => "!=",
+        BinOpKind::Lt => "<",
+        BinOpKind::Le => "<=",
+        BinOpKind::Gt => ">",
+        BinOpKind::Ge => ">=",
+    }
+}
+
+fn un_op_str(op: UnOpKind) -> &'static str {
+    match op {
+        UnOpKind::Neg => "-",
+        UnOpKind::Not => "!",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blastf_ast::ast::{Path, PathSegment, Statement};
+
+    fn var(name: &str) -> Expr {
+        Expr {
+            kind: ExprKind::Variable(Path {
+                segments: vec![PathSegment {
+                    ident: name.to_string(),
+                }],
+            }),
+        }
+    }
+
+    fn say_hi() -> Statement {
+        Statement {
+            kind: StatementKind::Expr(blastf_ast::ptr::P::new(var("say_hi"))),
+        }
+    }
+
+    #[test]
+    fn test_lower_if_without_else() {
+        let mut lowerer = Lowerer::new();
+        let expr = Expr {
+            kind: ExprKind::If {
+                cond: blastf_ast::ptr::P::new(var("flag")),
+                then: blastf_ast::ptr::P::new(blastf_ast::ast::Block {
+                    stmts: vec![say_hi()],
+                }),
+                els: None,
+            },
+        };
+
+        let instruction = lowerer.lower_expr(&expr).unwrap();
+        assert_eq!(
+            format!("{}", instruction),
+            "execute if flag run {\nsay_hi\n}"
+        );
+        assert!(lowerer.take_generated().is_empty());
+    }
+
+    #[test]
+    fn test_lower_if_with_else() {
+        let mut lowerer = Lowerer::new();
+        let expr = Expr {
+            kind: ExprKind::If {
+                cond: blastf_ast::ptr::P::new(var("flag")),
+                then: blastf_ast::ptr::P::new(blastf_ast::ast::Block {
+                    stmts: vec![say_hi()],
+                }),
+                els: Some(blastf_ast::ptr::P::new(var("say_bye"))),
+            },
+        };
+
+        let instruction = lowerer.lower_expr(&expr).unwrap();
+        assert_eq!(
+            format!("{}", instruction),
+            "{\nexecute if flag run {\nsay_hi\n}\nexecute unless flag run say_bye\n}"
+        );
+        assert!(lowerer.take_generated().is_empty());
+    }
+
+    #[test]
+    fn test_lower_while_generates_recursive_function() {
+        let mut lowerer = Lowerer::new();
+        let expr = Expr {
+            kind: ExprKind::While {
+                cond: blastf_ast::ptr::P::new(var("flag")),
+                body: blastf_ast::ptr::P::new(blastf_ast::ast::Block {
+                    stmts: vec![say_hi()],
+                }),
+            },
+        };
+
+        let instruction = lowerer.lower_expr(&expr).unwrap();
+        assert_eq!(
+            format!("{}", instruction),
+            "execute if flag run function __loop_0 "
+        );
+
+        let generated = lowerer.take_generated();
+        assert_eq!(generated.len(), 1);
+        assert_eq!(
+            format!("{}", generated[0]),
+            "__loop_0 {\nsay_hi\nexecute if flag run function __loop_0 \n}"
+        );
+    }
+
+    #[test]
+    fn test_lower_loop_generates_self_calling_function() {
+        let mut lowerer = Lowerer::new();
+        let expr = Expr {
+            kind: ExprKind::Loop(blastf_ast::ptr::P::new(blastf_ast::ast::Block {
+                stmts: vec![say_hi()],
+            })),
+        };
+
+        let instruction = lowerer.lower_expr(&expr).unwrap();
+        assert_eq!(format!("{}", instruction), "function __loop_0 ");
+
+        let generated = lowerer.take_generated();
+        assert_eq!(generated.len(), 1);
+        assert_eq!(
+            format!("{}", generated[0]),
+            "__loop_0 {\nsay_hi\nfunction __loop_0 \n}"
+        );
+    }
+
+    #[test]
+    fn test_stringify_expr_rejects_control_flow() {
+        let cond = Expr {
+            kind: ExprKind::Block(blastf_ast::ast::Block { stmts: vec![] }),
+        };
+        assert_eq!(stringify_expr(&cond), Err(LowerError::InvalidCondition));
+    }
+
+    #[test]
+    fn test_lower_if_rejects_control_flow_condition() {
+        let mut lowerer = Lowerer::new();
+        let expr = Expr {
+            kind: ExprKind::If {
+                cond: blastf_ast::ptr::P::new(Expr {
+                    kind: ExprKind::Block(blastf_ast::ast::Block { stmts: vec![] }),
+                }),
+                then: blastf_ast::ptr::P::new(blastf_ast::ast::Block { stmts: vec![] }),
+                els: None,
+            },
+        };
+
+        assert!(matches!(
+            lowerer.lower_expr(&expr),
+            Err(LowerError::InvalidCondition)
+        ));
+    }
+
+    #[test]
+    fn test_lower_block_rejects_let_bindings() {
+        use blastf_ast::ast::{LocalBind, LocalBindKind};
+
+        let mut lowerer = Lowerer::new();
+        let block = blastf_ast::ast::Block {
+            stmts: vec![Statement {
+                kind: StatementKind::Let(blastf_ast::ptr::P::new(LocalBind {
+                    ident: "x".to_string(),
+                    ty: None,
+                    kind: LocalBindKind::Decl,
+                })),
+            }],
+        };
+
+        assert!(matches!(
+            lowerer.lower_block(&block),
+            Err(LowerError::UnsupportedStatement)
+        ));
+    }
+}